@@ -0,0 +1,198 @@
+//! A builder for `blit`/`blit_sub` flags, plus safe wrappers taking sprite
+//! slices instead of raw pointers.
+
+use crate::{blit, blit_sub, BLIT_1BPP, BLIT_2BPP, BLIT_FLIP_H, BLIT_FLIP_V, BLIT_ROTATE};
+
+/// The bit depth used to encode a sprite passed to [`blit_safe`]/
+/// [`blit_sub_safe`].
+///
+/// See [`crate::BLIT_1BPP`] and [`crate::BLIT_2BPP`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlitFormat {
+    /// One bit per pixel. See [`crate::BLIT_1BPP`].
+    #[default]
+    OneBpp,
+    /// Two bits per pixel. See [`crate::BLIT_2BPP`].
+    TwoBpp,
+}
+
+/// A builder for the `flags` argument to [`blit_safe`] and
+/// [`blit_sub_safe`].
+///
+/// Defaults to 1BPP with no flipping or rotation, matching the bit pattern
+/// `0b0000`. Call [`BlitFlags::to_raw`] to pack the builder into the raw
+/// `0bRVHF` layout expected by [`blit`]/[`blit_sub`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlitFlags {
+    format: BlitFormat,
+    flip_h: bool,
+    flip_v: bool,
+    rotate: bool,
+}
+
+impl BlitFlags {
+    /// Creates a `BlitFlags` with the default flags: 1BPP, no flipping, no
+    /// rotation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the bit depth used to encode the sprite.
+    pub fn format(mut self, format: BlitFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets whether the sprite is flipped horizontally.
+    pub fn flip_h(mut self, flip_h: bool) -> Self {
+        self.flip_h = flip_h;
+        self
+    }
+
+    /// Sets whether the sprite is flipped vertically.
+    pub fn flip_v(mut self, flip_v: bool) -> Self {
+        self.flip_v = flip_v;
+        self
+    }
+
+    /// Sets whether the sprite is rotated 90° counterclockwise.
+    pub fn rotate(mut self, rotate: bool) -> Self {
+        self.rotate = rotate;
+        self
+    }
+
+    /// Packs this builder into the raw `0bRVHF` flags layout expected by
+    /// [`blit`] and [`blit_sub`].
+    pub fn to_raw(self) -> u32 {
+        let mut flags = match self.format {
+            BlitFormat::OneBpp => BLIT_1BPP,
+            BlitFormat::TwoBpp => BLIT_2BPP,
+        };
+
+        if self.flip_h {
+            flags |= BLIT_FLIP_H;
+        }
+        if self.flip_v {
+            flags |= BLIT_FLIP_V;
+        }
+        if self.rotate {
+            flags |= BLIT_ROTATE;
+        }
+
+        flags
+    }
+
+    fn bits_per_pixel(self) -> u64 {
+        match self.format {
+            BlitFormat::OneBpp => 1,
+            BlitFormat::TwoBpp => 2,
+        }
+    }
+}
+
+/// A safe wrapper over [`blit`], taking a sprite slice and a [`BlitFlags`]
+/// instead of a raw pointer and a `u32`.
+///
+/// Asserts that `sprite` is large enough to hold a `width`x`height` sprite
+/// at the bit depth selected by `flags`, so out-of-bounds sprite reads are
+/// caught even in release builds, where this is the only thing standing
+/// between safe caller code and an out-of-bounds read by the raw [`blit`].
+pub fn blit_safe(sprite: &[u8], x: i32, y: i32, width: u32, height: u32, flags: BlitFlags) {
+    let required_bits = width as u64 * height as u64 * flags.bits_per_pixel();
+    assert!(
+        sprite.len() as u64 * 8 >= required_bits,
+        "sprite slice of {} bytes is too small for a {width}x{height} sprite at {:?}",
+        sprite.len(),
+        flags.format,
+    );
+
+    unsafe {
+        blit(sprite.as_ptr(), x, y, width, height, flags.to_raw());
+    }
+}
+
+/// A safe wrapper over [`blit_sub`], taking a sprite slice and a
+/// [`BlitFlags`] instead of a raw pointer and a `u32`.
+///
+/// Asserts that `sprite` is large enough to hold `stride` columns for
+/// `src_y + height` rows at the bit depth selected by `flags`, so
+/// out-of-bounds sprite reads are caught even in release builds, where this
+/// is the only thing standing between safe caller code and an out-of-bounds
+/// read by the raw [`blit_sub`].
+#[allow(clippy::too_many_arguments)]
+pub fn blit_sub_safe(
+    sprite: &[u8],
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    src_x: u32,
+    src_y: u32,
+    stride: u32,
+    flags: BlitFlags,
+) {
+    let required_bits = stride as u64 * (src_y as u64 + height as u64) * flags.bits_per_pixel();
+    assert!(
+        sprite.len() as u64 * 8 >= required_bits,
+        "sprite slice of {} bytes is too small for a {width}x{height} subregion at ({src_x}, \
+         {src_y}) of a sprite with stride {stride} at {:?}",
+        sprite.len(),
+        flags.format,
+    );
+
+    unsafe {
+        blit_sub(
+            sprite.as_ptr(),
+            x,
+            y,
+            width,
+            height,
+            src_x,
+            src_y,
+            stride,
+            flags.to_raw(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_1bpp_with_no_flags() {
+        assert_eq!(BlitFlags::new().to_raw(), BLIT_1BPP);
+    }
+
+    #[test]
+    fn format_selects_bit_depth() {
+        assert_eq!(BlitFlags::new().format(BlitFormat::TwoBpp).to_raw(), BLIT_2BPP);
+    }
+
+    #[test]
+    fn flip_h_sets_its_bit() {
+        assert_eq!(BlitFlags::new().flip_h(true).to_raw(), BLIT_FLIP_H);
+    }
+
+    #[test]
+    fn flip_v_sets_its_bit() {
+        assert_eq!(BlitFlags::new().flip_v(true).to_raw(), BLIT_FLIP_V);
+    }
+
+    #[test]
+    fn rotate_sets_its_bit() {
+        assert_eq!(BlitFlags::new().rotate(true).to_raw(), BLIT_ROTATE);
+    }
+
+    #[test]
+    fn flags_combine() {
+        let flags = BlitFlags::new()
+            .format(BlitFormat::TwoBpp)
+            .flip_h(true)
+            .flip_v(true)
+            .rotate(true)
+            .to_raw();
+
+        assert_eq!(flags, BLIT_2BPP | BLIT_FLIP_H | BLIT_FLIP_V | BLIT_ROTATE);
+    }
+}
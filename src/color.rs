@@ -0,0 +1,252 @@
+//! A typed API over the color palette and drawing colors.
+
+use crate::Memory;
+
+/// A single color from the [`Palette`].
+///
+/// Stored internally in the same byte order as [`crate::PALETTE`] so that it
+/// can be placed directly into [`Memory::palette`]; use [`Color::new`] to
+/// construct one from `r`/`g`/`b` channels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Color {
+    b: u8,
+    g: u8,
+    r: u8,
+    _reserved: u8,
+}
+
+impl Color {
+    /// Creates a `Color` from its red, green, and blue channels.
+    pub fn new(r: u8, g: u8, b: u8) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            _reserved: 0,
+        }
+    }
+
+    /// Returns the red channel.
+    pub fn r(self) -> u8 {
+        self.r
+    }
+
+    /// Returns the green channel.
+    pub fn g(self) -> u8 {
+        self.g
+    }
+
+    /// Returns the blue channel.
+    pub fn b(self) -> u8 {
+        self.b
+    }
+
+    /// Packs this color into the `0xXXRRGGBB` layout used by
+    /// [`crate::PALETTE`].
+    pub fn to_packed(self) -> u32 {
+        (self.r as u32) << 16 | (self.g as u32) << 8 | self.b as u32
+    }
+
+    /// Unpacks a color from the `0xXXRRGGBB` layout used by
+    /// [`crate::PALETTE`].
+    pub fn from_packed(packed: u32) -> Self {
+        Self::new((packed >> 16) as u8, (packed >> 8) as u8, packed as u8)
+    }
+}
+
+/// A typed wrapper over the four-color palette.
+///
+/// See [`crate::PALETTE`].
+pub struct Palette;
+
+impl Palette {
+    /// Returns the color at `index` (0 to 3) in the palette.
+    pub fn get(index: usize) -> Color {
+        unsafe { Memory::get() }.palette[index]
+    }
+
+    /// Sets the color at `index` (0 to 3) in the palette.
+    pub fn set(index: usize, color: Color) {
+        unsafe { Memory::get() }.palette[index] = color;
+    }
+}
+
+/// One of the four palette colors, or transparency, as encoded by a nibble
+/// of [`DrawColors`].
+///
+/// See [`crate::DRAW_COLORS`] for the 1-4/0 semantics this mirrors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorIndex {
+    /// No color; the corresponding pixels are not drawn.
+    #[default]
+    Transparent,
+    /// The color at index 0 of the [`Palette`].
+    P0,
+    /// The color at index 1 of the [`Palette`].
+    P1,
+    /// The color at index 2 of the [`Palette`].
+    P2,
+    /// The color at index 3 of the [`Palette`].
+    P3,
+}
+
+impl ColorIndex {
+    fn to_nibble(self) -> u16 {
+        match self {
+            ColorIndex::Transparent => 0,
+            ColorIndex::P0 => 1,
+            ColorIndex::P1 => 2,
+            ColorIndex::P2 => 3,
+            ColorIndex::P3 => 4,
+        }
+    }
+
+    fn from_nibble(nibble: u16) -> Self {
+        match nibble & 0xf {
+            1 => ColorIndex::P0,
+            2 => ColorIndex::P1,
+            3 => ColorIndex::P2,
+            4 => ColorIndex::P3,
+            _ => ColorIndex::Transparent,
+        }
+    }
+
+    /// Packs this color index into the raw 2-bit pixel value stored in the
+    /// framebuffer.
+    ///
+    /// The framebuffer has no transparent pixels, so
+    /// [`ColorIndex::Transparent`] packs to the same value as
+    /// [`ColorIndex::P0`].
+    pub(crate) fn to_pixel(self) -> u8 {
+        match self {
+            ColorIndex::Transparent | ColorIndex::P0 => 0,
+            ColorIndex::P1 => 1,
+            ColorIndex::P2 => 2,
+            ColorIndex::P3 => 3,
+        }
+    }
+
+    /// Unpacks a color index from the raw 2-bit pixel value stored in the
+    /// framebuffer.
+    pub(crate) fn from_pixel(pixel: u8) -> Self {
+        match pixel & 0b11 {
+            0 => ColorIndex::P0,
+            1 => ColorIndex::P1,
+            2 => ColorIndex::P2,
+            _ => ColorIndex::P3,
+        }
+    }
+}
+
+/// The drawing colors, as they appear in [`Memory::draw_colors`].
+///
+/// See [`crate::DRAW_COLORS`] for the bit layout. The setters take `self` by
+/// value and return the updated value, rather than mutating in place,
+/// because [`Memory`] is a packed struct: taking `&mut self` through
+/// `Memory::get().draw_colors` would require an unaligned reference to a
+/// packed field, which does not compile. Read the field out, build up the
+/// new value, then write the whole field back in one assignment:
+///
+/// ```no_run
+/// // `no_run`: this only makes sense on the wasm32 target WASM-4 carts are
+/// // compiled for, where address 0 is valid linear memory; compiled (but
+/// // not executed) here to guard against this pattern failing to compile.
+/// use nkd_w4_prim::{ColorIndex, Memory};
+///
+/// let mut draw_colors = unsafe { Memory::get() }.draw_colors;
+/// draw_colors = draw_colors.set_primary(ColorIndex::P2);
+/// unsafe { Memory::get() }.draw_colors = draw_colors;
+///
+/// assert_eq!(unsafe { Memory::get() }.draw_colors.primary(), ColorIndex::P2);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DrawColors(u16);
+
+impl DrawColors {
+    /// Returns the first drawing color.
+    pub fn primary(self) -> ColorIndex {
+        ColorIndex::from_nibble(self.0)
+    }
+
+    /// Sets the first drawing color, returning the updated value.
+    pub fn set_primary(self, color: ColorIndex) -> Self {
+        self.set_nibble(0, color)
+    }
+
+    /// Returns the second drawing color.
+    pub fn secondary(self) -> ColorIndex {
+        ColorIndex::from_nibble(self.0 >> 4)
+    }
+
+    /// Sets the second drawing color, returning the updated value.
+    pub fn set_secondary(self, color: ColorIndex) -> Self {
+        self.set_nibble(1, color)
+    }
+
+    /// Returns the third drawing color.
+    pub fn tertiary(self) -> ColorIndex {
+        ColorIndex::from_nibble(self.0 >> 8)
+    }
+
+    /// Sets the third drawing color, returning the updated value.
+    pub fn set_tertiary(self, color: ColorIndex) -> Self {
+        self.set_nibble(2, color)
+    }
+
+    /// Returns the fourth drawing color.
+    pub fn quaternary(self) -> ColorIndex {
+        ColorIndex::from_nibble(self.0 >> 12)
+    }
+
+    /// Sets the fourth drawing color, returning the updated value.
+    pub fn set_quaternary(self, color: ColorIndex) -> Self {
+        self.set_nibble(3, color)
+    }
+
+    fn set_nibble(mut self, slot: u16, color: ColorIndex) -> Self {
+        let shift = slot * 4;
+        self.0 = (self.0 & !(0xf << shift)) | (color.to_nibble() << shift);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_round_trips_through_packed() {
+        let color = Color::new(0x12, 0x34, 0x56);
+        assert_eq!(Color::from_packed(color.to_packed()), color);
+    }
+
+    #[test]
+    fn color_packs_into_0xxrrggbb() {
+        assert_eq!(Color::new(0x12, 0x34, 0x56).to_packed(), 0x00123456);
+    }
+
+    #[test]
+    fn draw_colors_default_is_all_transparent() {
+        let draw_colors = DrawColors::default();
+        assert_eq!(draw_colors.primary(), ColorIndex::Transparent);
+        assert_eq!(draw_colors.secondary(), ColorIndex::Transparent);
+        assert_eq!(draw_colors.tertiary(), ColorIndex::Transparent);
+        assert_eq!(draw_colors.quaternary(), ColorIndex::Transparent);
+    }
+
+    #[test]
+    fn draw_colors_setters_only_touch_their_own_nibble() {
+        let draw_colors = DrawColors::default()
+            .set_primary(ColorIndex::P0)
+            .set_secondary(ColorIndex::P2)
+            .set_tertiary(ColorIndex::P1)
+            .set_quaternary(ColorIndex::P3);
+
+        assert_eq!(draw_colors.primary(), ColorIndex::P0);
+        assert_eq!(draw_colors.secondary(), ColorIndex::P2);
+        assert_eq!(draw_colors.tertiary(), ColorIndex::P1);
+        assert_eq!(draw_colors.quaternary(), ColorIndex::P3);
+    }
+}
@@ -0,0 +1,104 @@
+//! A safe, pixel-exact API over the framebuffer.
+
+use crate::{ColorIndex, Memory, SCREEN_SIZE};
+
+/// An axis-aligned rectangle used to constrain framebuffer writes.
+///
+/// Defaults to covering the entire screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClipRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ClipRect {
+    /// A clip rectangle covering the entire screen.
+    pub fn full_screen() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            width: SCREEN_SIZE,
+            height: SCREEN_SIZE,
+        }
+    }
+
+    fn contains(self, x: i32, y: i32) -> bool {
+        x >= self.x
+            && y >= self.y
+            && x < self.x + self.width as i32
+            && y < self.y + self.height as i32
+    }
+}
+
+impl Default for ClipRect {
+    fn default() -> Self {
+        Self::full_screen()
+    }
+}
+
+/// A safe wrapper over [`crate::FRAMEBUFFER`], handling the 2-bit pixel
+/// packing internally.
+pub struct Framebuffer;
+
+impl Framebuffer {
+    /// Returns the color of the pixel at `(x, y)`.
+    ///
+    /// Returns [`ColorIndex::P0`] if the coordinates are off-screen.
+    pub fn get_pixel(x: i32, y: i32) -> ColorIndex {
+        if !in_bounds(x, y) {
+            return ColorIndex::P0;
+        }
+
+        let (byte, shift) = locate(x);
+        let row = &unsafe { Memory::get() }.framebuffer[y as usize];
+        ColorIndex::from_pixel((row[byte] >> shift) & 0b11)
+    }
+
+    /// Sets the pixel at `(x, y)` to `color`.
+    ///
+    /// Off-screen writes are silently clipped, as the hardware drawing
+    /// functions do.
+    pub fn set_pixel(x: i32, y: i32, color: ColorIndex) {
+        if !in_bounds(x, y) {
+            return;
+        }
+
+        let (byte, shift) = locate(x);
+        let row = &mut unsafe { Memory::get() }.framebuffer[y as usize];
+        row[byte] = (row[byte] & !(0b11 << shift)) | (color.to_pixel() << shift);
+    }
+
+    /// Fills the `width`x`height` rectangle at `(x, y)` with `color`,
+    /// clipped to `clip`.
+    pub fn fill_rect(x: i32, y: i32, width: u32, height: u32, color: ColorIndex, clip: ClipRect) {
+        for row in y..y + height as i32 {
+            for col in x..x + width as i32 {
+                if clip.contains(col, row) {
+                    Self::set_pixel(col, row, color);
+                }
+            }
+        }
+    }
+
+    /// Draws a horizontal line starting at `(x, y)` of length `len`, clipped
+    /// to `clip`.
+    pub fn hline(x: i32, y: i32, len: u32, color: ColorIndex, clip: ClipRect) {
+        Self::fill_rect(x, y, len, 1, color, clip);
+    }
+
+    /// Draws a vertical line starting at `(x, y)` of length `len`, clipped
+    /// to `clip`.
+    pub fn vline(x: i32, y: i32, len: u32, color: ColorIndex, clip: ClipRect) {
+        Self::fill_rect(x, y, 1, len, color, clip);
+    }
+}
+
+fn in_bounds(x: i32, y: i32) -> bool {
+    x >= 0 && y >= 0 && (x as u32) < SCREEN_SIZE && (y as u32) < SCREEN_SIZE
+}
+
+fn locate(x: i32) -> (usize, u8) {
+    ((x / 4) as usize, ((x % 4) * 2) as u8)
+}
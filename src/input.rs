@@ -0,0 +1,168 @@
+//! Typed accessors for gamepad and mouse input, plus frame-to-frame edge
+//! detection.
+
+use crate::{
+    Memory, GAMEPAD_DOWN, GAMEPAD_LEFT, GAMEPAD_RIGHT, GAMEPAD_UP, GAMEPAD_X, GAMEPAD_Z,
+    MOUSE_LEFT, MOUSE_MIDDLE, MOUSE_RIGHT,
+};
+
+/// A single gamepad, as it appears in [`Memory::gamepads`].
+///
+/// See [`crate::GAMEPADS`] for the bit layout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Gamepad(u8);
+
+impl Gamepad {
+    /// Returns the raw byte underlying this gamepad's state.
+    pub fn raw(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if the X button is held.
+    pub fn x(self) -> bool {
+        self.0 & GAMEPAD_X != 0
+    }
+
+    /// Returns `true` if the Z button is held.
+    pub fn z(self) -> bool {
+        self.0 & GAMEPAD_Z != 0
+    }
+
+    /// Returns `true` if the Left button is held.
+    pub fn left(self) -> bool {
+        self.0 & GAMEPAD_LEFT != 0
+    }
+
+    /// Returns `true` if the Right button is held.
+    pub fn right(self) -> bool {
+        self.0 & GAMEPAD_RIGHT != 0
+    }
+
+    /// Returns `true` if the Up button is held.
+    pub fn up(self) -> bool {
+        self.0 & GAMEPAD_UP != 0
+    }
+
+    /// Returns `true` if the Down button is held.
+    pub fn down(self) -> bool {
+        self.0 & GAMEPAD_DOWN != 0
+    }
+}
+
+/// The mouse, as it appears in [`Memory::mouse`].
+///
+/// See [`crate::MOUSE_X`], [`crate::MOUSE_Y`], and [`crate::MOUSE_BUTTONS`].
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(C, packed)]
+pub struct Mouse {
+    x: i16,
+    y: i16,
+    buttons: u8,
+}
+
+impl Mouse {
+    /// Returns the x position of the mouse.
+    pub fn x(self) -> i16 {
+        self.x
+    }
+
+    /// Returns the y position of the mouse.
+    pub fn y(self) -> i16 {
+        self.y
+    }
+
+    /// Returns the raw byte underlying the mouse button state.
+    pub fn buttons_raw(self) -> u8 {
+        self.buttons
+    }
+
+    /// Returns `true` if the left mouse button is held.
+    pub fn left(self) -> bool {
+        self.buttons & MOUSE_LEFT != 0
+    }
+
+    /// Returns `true` if the right mouse button is held.
+    pub fn right(self) -> bool {
+        self.buttons & MOUSE_RIGHT != 0
+    }
+
+    /// Returns `true` if the middle mouse button is held.
+    pub fn middle(self) -> bool {
+        self.buttons & MOUSE_MIDDLE != 0
+    }
+}
+
+/// Frame-to-frame input state, supporting edge detection.
+///
+/// Call [`InputState::update`] once per frame, before querying
+/// [`InputState::just_pressed`], [`InputState::just_released`],
+/// [`InputState::mouse_just_pressed`], or
+/// [`InputState::mouse_just_released`]. These methods diff the current
+/// frame's raw gamepad/mouse bytes against the previous frame's, so callers
+/// no longer need to track button state by hand.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InputState {
+    previous_gamepads: [u8; 4],
+    current_gamepads: [u8; 4],
+    previous_mouse_buttons: u8,
+    current_mouse_buttons: u8,
+}
+
+impl InputState {
+    /// Creates an `InputState` with no input recorded for either frame.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advances the input state by one frame.
+    ///
+    /// This should be called exactly once per frame, before the state is
+    /// queried for that frame.
+    pub fn update(&mut self) {
+        self.previous_gamepads = self.current_gamepads;
+        self.previous_mouse_buttons = self.current_mouse_buttons;
+
+        let memory = unsafe { Memory::get() };
+        for (slot, gamepad) in self.current_gamepads.iter_mut().zip(memory.gamepads) {
+            *slot = gamepad.raw();
+        }
+        self.current_mouse_buttons = memory.mouse.buttons_raw();
+    }
+
+    /// Returns `true` if `button` is held on gamepad `player` this frame but
+    /// was not held last frame.
+    ///
+    /// `player` is an index from 0 to 3. `button` should be one of the
+    /// `GAMEPAD_*` bitmasks, e.g. [`crate::GAMEPAD_X`].
+    pub fn just_pressed(&self, player: usize, button: u8) -> bool {
+        self.current_gamepads[player] & button != 0 && self.previous_gamepads[player] & button == 0
+    }
+
+    /// Returns `true` if `button` is not held on gamepad `player` this frame
+    /// but was held last frame.
+    ///
+    /// `player` is an index from 0 to 3. `button` should be one of the
+    /// `GAMEPAD_*` bitmasks, e.g. [`crate::GAMEPAD_X`].
+    pub fn just_released(&self, player: usize, button: u8) -> bool {
+        self.current_gamepads[player] & button == 0 && self.previous_gamepads[player] & button != 0
+    }
+
+    /// Returns `true` if `button` is held on the mouse this frame but was
+    /// not held last frame.
+    ///
+    /// `button` should be one of the `MOUSE_*` bitmasks, e.g.
+    /// [`crate::MOUSE_LEFT`].
+    pub fn mouse_just_pressed(&self, button: u8) -> bool {
+        self.current_mouse_buttons & button != 0 && self.previous_mouse_buttons & button == 0
+    }
+
+    /// Returns `true` if `button` is not held on the mouse this frame but
+    /// was held last frame.
+    ///
+    /// `button` should be one of the `MOUSE_*` bitmasks, e.g.
+    /// [`crate::MOUSE_LEFT`].
+    pub fn mouse_just_released(&self, button: u8) -> bool {
+        self.current_mouse_buttons & button == 0 && self.previous_mouse_buttons & button != 0
+    }
+}
@@ -1,3 +1,19 @@
+// Modules
+
+mod blit;
+mod color;
+mod framebuffer;
+mod input;
+mod memory;
+mod tone;
+
+pub use blit::{blit_safe, blit_sub_safe, BlitFlags, BlitFormat};
+pub use color::{Color, ColorIndex, DrawColors, Palette};
+pub use framebuffer::{ClipRect, Framebuffer};
+pub use input::{Gamepad, InputState, Mouse};
+pub use memory::{Memory, Netplay, SystemFlags};
+pub use tone::{Channel, DutyCycle, Pan, Tone};
+
 // Platform Constants
 
 /// The height and width of the screen, in pixels.
@@ -0,0 +1,74 @@
+//! A typed view over the WASM-4 memory-mapped I/O region.
+//!
+//! Everything in this module mirrors the raw addresses exposed at the crate
+//! root ([`crate::PALETTE`], [`crate::DRAW_COLORS`], [`crate::GAMEPADS`],
+//! etc.) as a single [`Memory`] struct, so callers can use field access
+//! instead of juggling pointers and magic offsets.
+
+use core::mem::offset_of;
+
+use crate::color::{Color, DrawColors};
+use crate::input::{Gamepad, Mouse};
+
+/// The system flags, as they appear in [`Memory::system`].
+///
+/// See [`crate::SYSTEM_FLAGS`] for the bit layout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SystemFlags(u8);
+
+/// The NetPlay multiplayer state, as it appears in [`Memory::netplay`].
+///
+/// See [`crate::NETPLAY`] for the bit layout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Netplay(u8);
+
+/// A typed view over the WASM-4 memory-mapped I/O region, from address `0`
+/// through the end of the framebuffer at `0x19a0`. The rest of the 64 KiB
+/// address space is not covered by this struct.
+///
+/// This struct lays its fields out at the same offsets as the raw MMIO
+/// addresses documented at the crate root, so that
+/// `unsafe { Memory::get() }.palette`, `unsafe { Memory::get() }.draw_colors`,
+/// etc. may be used instead of the individual [`crate::PALETTE`],
+/// [`crate::DRAW_COLORS`], and other pointers.
+#[repr(C, packed)]
+pub struct Memory {
+    _padding: [u8; 4],
+    pub palette: [Color; 4],
+    pub draw_colors: DrawColors,
+    pub gamepads: [Gamepad; 4],
+    pub mouse: Mouse,
+    pub system: SystemFlags,
+    pub netplay: Netplay,
+    _reserved: [u8; 0x7f],
+    pub framebuffer: [[u8; 40]; 160],
+}
+
+impl Memory {
+    /// Returns a reference to the WASM-4 memory-mapped I/O region.
+    ///
+    /// This materializes the [`Memory`] struct at address `0`, giving field
+    /// access to every MMIO location described at the crate root.
+    ///
+    /// # Safety
+    ///
+    /// The returned reference aliases the single, shared MMIO region; it is
+    /// up to the caller to ensure that at most one live reference produced
+    /// by this function exists at a time, and that it does not outlive the
+    /// scope in which it is used. Prefer calling this once per access and
+    /// dropping the reference immediately rather than holding onto it.
+    #[allow(deref_nullptr)]
+    pub unsafe fn get() -> &'static mut Memory {
+        unsafe { &mut *core::ptr::null_mut::<Memory>() }
+    }
+}
+
+const _: () = assert!(offset_of!(Memory, palette) == 0x04);
+const _: () = assert!(offset_of!(Memory, draw_colors) == 0x14);
+const _: () = assert!(offset_of!(Memory, gamepads) == 0x16);
+const _: () = assert!(offset_of!(Memory, mouse) == 0x1a);
+const _: () = assert!(offset_of!(Memory, system) == 0x1f);
+const _: () = assert!(offset_of!(Memory, netplay) == 0x20);
+const _: () = assert!(offset_of!(Memory, framebuffer) == 0xa0);
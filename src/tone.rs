@@ -0,0 +1,258 @@
+//! A builder for the `tone` sound function, encoding frequency sweeps, ADSR
+//! envelopes, and channel/duty/pan selection.
+
+use crate::{
+    tone as raw_tone, TONE_DC_12_5, TONE_DC_25, TONE_DC_50, TONE_DC_75, TONE_NOISE,
+    TONE_PAN_CENTER, TONE_PAN_LEFT, TONE_PAN_RIGHT, TONE_PULSE1, TONE_PULSE2, TONE_TRIANGLE,
+};
+
+/// The waveform played by a [`Tone`].
+///
+/// See [`crate::TONE_PULSE1`], [`crate::TONE_PULSE2`], [`crate::TONE_TRIANGLE`],
+/// and [`crate::TONE_NOISE`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Channel {
+    /// The first pulse (square) wave.
+    #[default]
+    Pulse1,
+    /// The second pulse (square) wave.
+    Pulse2,
+    /// The triangle wave.
+    Triangle,
+    /// The noise wave.
+    Noise,
+}
+
+impl Channel {
+    fn to_raw(self) -> u32 {
+        match self {
+            Channel::Pulse1 => TONE_PULSE1,
+            Channel::Pulse2 => TONE_PULSE2,
+            Channel::Triangle => TONE_TRIANGLE,
+            Channel::Noise => TONE_NOISE,
+        }
+    }
+}
+
+/// The pulse wave duty cycle. Only meaningful for [`Channel::Pulse1`] and
+/// [`Channel::Pulse2`].
+///
+/// See [`crate::TONE_DC_12_5`], [`crate::TONE_DC_25`], [`crate::TONE_DC_50`],
+/// and [`crate::TONE_DC_75`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DutyCycle {
+    /// A 12.5% duty cycle.
+    #[default]
+    Duty12_5,
+    /// A 25% duty cycle.
+    Duty25,
+    /// A 50% duty cycle.
+    Duty50,
+    /// A 75% duty cycle.
+    Duty75,
+}
+
+impl DutyCycle {
+    fn to_raw(self) -> u32 {
+        match self {
+            DutyCycle::Duty12_5 => TONE_DC_12_5,
+            DutyCycle::Duty25 => TONE_DC_25,
+            DutyCycle::Duty50 => TONE_DC_50,
+            DutyCycle::Duty75 => TONE_DC_75,
+        }
+    }
+}
+
+/// The stereo pan of a [`Tone`].
+///
+/// See [`crate::TONE_PAN_CENTER`], [`crate::TONE_PAN_LEFT`], and
+/// [`crate::TONE_PAN_RIGHT`]. The invalid pan value `3` has no corresponding
+/// variant, so it cannot be constructed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pan {
+    /// Center pan.
+    #[default]
+    Center,
+    /// Left pan.
+    Left,
+    /// Right pan.
+    Right,
+}
+
+impl Pan {
+    fn to_raw(self) -> u32 {
+        match self {
+            Pan::Center => TONE_PAN_CENTER,
+            Pan::Left => TONE_PAN_LEFT,
+            Pan::Right => TONE_PAN_RIGHT,
+        }
+    }
+}
+
+/// A builder for a sound tone played via [`crate::tone`].
+///
+/// Assembles the packed `frequency`, `duration`, `volume`, and `flags`
+/// arguments from individually named fields, instead of requiring callers
+/// to hand-pack four `u32`s across four independent bit layouts.
+#[derive(Clone, Copy, Debug)]
+pub struct Tone {
+    start_hz: u16,
+    end_hz: u16,
+    attack: u8,
+    decay: u8,
+    sustain_duration: u8,
+    release: u8,
+    sustain_volume: u8,
+    attack_volume: u8,
+    channel: Channel,
+    duty: DutyCycle,
+    pan: Pan,
+}
+
+impl Default for Tone {
+    fn default() -> Self {
+        Self {
+            start_hz: 0,
+            end_hz: 0,
+            attack: 0,
+            decay: 0,
+            sustain_duration: 0,
+            release: 0,
+            sustain_volume: 100,
+            attack_volume: 0,
+            channel: Channel::default(),
+            duty: DutyCycle::default(),
+            pan: Pan::default(),
+        }
+    }
+}
+
+impl Tone {
+    /// Creates a `Tone` with a constant 0 Hz frequency, no envelope, full
+    /// sustain volume, and the default channel, duty cycle, and pan.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the start frequency, in Hz.
+    pub fn frequency(mut self, start_hz: u16) -> Self {
+        self.start_hz = start_hz;
+        self
+    }
+
+    /// Sets the end frequency, in Hz.
+    ///
+    /// If set to a non-zero value, the tone's pitch will change linearly
+    /// from the start frequency to this frequency over its duration.
+    pub fn sweep_to(mut self, end_hz: u16) -> Self {
+        self.end_hz = end_hz;
+        self
+    }
+
+    /// Sets the attack, decay, sustain, and release durations of the ADSR
+    /// envelope, each in 1/60ths of a second.
+    pub fn envelope(mut self, attack: u8, decay: u8, sustain: u8, release: u8) -> Self {
+        self.attack = attack;
+        self.decay = decay;
+        self.sustain_duration = sustain;
+        self.release = release;
+        self
+    }
+
+    /// Sets the sustain volume, clamped to 0..=100.
+    pub fn volume(mut self, sustain: u8) -> Self {
+        self.sustain_volume = sustain.min(100);
+        self
+    }
+
+    /// Sets the attack volume, clamped to 0..=100.
+    ///
+    /// If left at 0, the attack volume defaults to 100.
+    pub fn attack_volume(mut self, attack: u8) -> Self {
+        self.attack_volume = attack.min(100);
+        self
+    }
+
+    /// Sets the channel (waveform) of the tone.
+    pub fn channel(mut self, channel: Channel) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Sets the pulse wave duty cycle. Only meaningful for
+    /// [`Channel::Pulse1`] and [`Channel::Pulse2`].
+    pub fn duty(mut self, duty: DutyCycle) -> Self {
+        self.duty = duty;
+        self
+    }
+
+    /// Sets the stereo pan of the tone.
+    pub fn pan(mut self, pan: Pan) -> Self {
+        self.pan = pan;
+        self
+    }
+
+    /// Assembles the `(frequency, duration, volume, flags)` arguments
+    /// expected by the raw [`crate::tone`].
+    fn to_raw_args(self) -> (u32, u32, u32, u32) {
+        let frequency = self.start_hz as u32 | (self.end_hz as u32) << 16;
+        let duration = self.release as u32
+            | (self.sustain_duration as u32) << 8
+            | (self.decay as u32) << 16
+            | (self.attack as u32) << 24;
+        let volume = self.sustain_volume as u32 | (self.attack_volume as u32) << 16;
+        let flags = self.channel.to_raw() | self.duty.to_raw() | self.pan.to_raw();
+
+        (frequency, duration, volume, flags)
+    }
+
+    /// Plays this tone by assembling its packed arguments and calling the
+    /// raw [`crate::tone`].
+    pub fn play(self) {
+        let (frequency, duration, volume, flags) = self.to_raw_args();
+
+        unsafe {
+            raw_tone(frequency, duration, volume, flags);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_frequency_sweep() {
+        let (frequency, _, _, _) = Tone::new().frequency(440).sweep_to(220).to_raw_args();
+        assert_eq!(frequency, 440 | (220 << 16));
+    }
+
+    #[test]
+    fn packs_adsr_envelope() {
+        let (_, duration, _, _) = Tone::new().envelope(1, 2, 3, 4).to_raw_args();
+        assert_eq!(duration, 4 | (3 << 8) | (2 << 16) | (1 << 24));
+    }
+
+    #[test]
+    fn packs_volume() {
+        let (_, _, volume, _) = Tone::new().volume(80).attack_volume(90).to_raw_args();
+        assert_eq!(volume, 80 | (90 << 16));
+    }
+
+    #[test]
+    fn volume_clamps_to_100() {
+        let (_, _, volume, _) = Tone::new().volume(200).attack_volume(255).to_raw_args();
+        assert_eq!(volume, 100 | (100 << 16));
+    }
+
+    #[test]
+    fn packs_channel_duty_and_pan_flags() {
+        let (_, _, _, flags) = Tone::new()
+            .channel(Channel::Triangle)
+            .duty(DutyCycle::Duty50)
+            .pan(Pan::Left)
+            .to_raw_args();
+
+        assert_eq!(flags, TONE_TRIANGLE | TONE_DC_50 | TONE_PAN_LEFT);
+    }
+}